@@ -44,4 +44,64 @@ pub enum AccessRegistryError {
 
     #[msg("Array length mismatch")]
     ArrayLengthMismatch,
+
+    #[msg("Address is sanctioned by the configured oracle quorum")]
+    AddressSanctioned,
+
+    #[msg("Blacklist entry account does not match the expected PDA")]
+    InvalidBlacklistEntry,
+
+    #[msg("Return data would exceed the 1024-byte return-data cap")]
+    ReturnDataTooLarge,
+
+    #[msg("Program is already whitelisted")]
+    AlreadyWhitelisted,
+
+    #[msg("Whitelist is full")]
+    WhitelistFull,
+
+    #[msg("Whitelist entry not found")]
+    WhitelistEntryNotFound,
+
+    #[msg("Oracle is already configured")]
+    OracleAlreadyConfigured,
+
+    #[msg("Oracle list is full")]
+    OracleListFull,
+
+    #[msg("Oracle not found in the configured list")]
+    OracleEntryNotFound,
+
+    #[msg("Oracle account count does not match the configured oracle list")]
+    OracleAccountsMismatch,
+
+    #[msg("Duplicate address within the same batch")]
+    DuplicateAddressInBatch,
+
+    #[msg("Ownership transfer timelock has not elapsed yet")]
+    TransferTimelockNotElapsed,
+
+    #[msg("Transfer delay must be non-negative")]
+    InvalidTransferDelay,
+
+    #[msg("Quorum cannot exceed the number of configured oracles")]
+    InvalidQuorum,
+
+    #[msg("Address is already allowlisted")]
+    AlreadyAllowlisted,
+
+    #[msg("Address is not allowlisted")]
+    NotAllowlisted,
+
+    #[msg("Allowlist entry account does not match the expected PDA")]
+    InvalidAllowlistEntry,
+
+    #[msg("This instruction is disabled in the current build")]
+    FeatureNotEnabled,
+
+    #[msg("Registry is already paused")]
+    AlreadyPaused,
+
+    #[msg("Registry is not paused")]
+    NotPaused,
 }
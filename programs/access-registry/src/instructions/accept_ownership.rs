@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::AcceptOwnership;
+use crate::{AcceptOwnership, AccessRegistryError, AuditAction, AuditLogEntry};
 
 pub fn handler(ctx: Context<AcceptOwnership>) -> Result<()> {
     let registry = &mut ctx.accounts.registry;
@@ -8,14 +8,30 @@ pub fn handler(ctx: Context<AcceptOwnership>) -> Result<()> {
     // Validate caller is the pending owner
     require!(
         registry.pending_owner == ctx.accounts.signer.key(),
-        crate::AccessRegistryError::NotPendingOwner
+        AccessRegistryError::NotPendingOwner
+    );
+
+    // Validate the on-chain timelock has elapsed since the transfer was initiated
+    let now = registry.timelock_now()?;
+    require!(
+        now >= registry.transfer_initiated_at + registry.transfer_delay,
+        AccessRegistryError::TransferTimelockNotElapsed
     );
 
     // Transfer ownership
     registry.owner = registry.pending_owner;
     registry.pending_owner = Pubkey::default();
+    registry.transfer_initiated_at = 0;
 
     msg!("Ownership transferred to {}", registry.owner);
 
+    ctx.accounts.audit_log.push(AuditLogEntry {
+        timestamp: now,
+        actor: ctx.accounts.signer.key(),
+        target: registry.owner,
+        action: AuditAction::OwnershipTransferAccepted,
+        blacklisted: false,
+    });
+
     Ok(())
 }
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::{AccessRegistryError, AddOracle, MAX_ORACLES};
+
+pub fn handler(ctx: Context<AddOracle>, oracle: Pubkey) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+
+    require!(
+        !registry.oracles.contains(&oracle),
+        AccessRegistryError::OracleAlreadyConfigured
+    );
+    require!(
+        registry.oracles.len() < MAX_ORACLES,
+        AccessRegistryError::OracleListFull
+    );
+
+    registry.oracles.push(oracle);
+
+    msg!("Added sanction oracle: {}", oracle);
+
+    Ok(())
+}
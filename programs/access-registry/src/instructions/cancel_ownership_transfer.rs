@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{AccessRegistryError, AuditAction, AuditLogEntry, CancelOwnershipTransfer};
+
+pub fn handler(ctx: Context<CancelOwnershipTransfer>) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+
+    require!(
+        registry.has_pending_owner(),
+        AccessRegistryError::NoPendingTransfer
+    );
+
+    let cancelled_pending_owner = registry.pending_owner;
+    registry.pending_owner = Pubkey::default();
+    registry.transfer_initiated_at = 0;
+
+    msg!(
+        "Ownership transfer to {} cancelled",
+        cancelled_pending_owner
+    );
+
+    ctx.accounts.audit_log.push(AuditLogEntry {
+        timestamp: registry.timelock_now()?,
+        actor: ctx.accounts.authority.key(),
+        target: cancelled_pending_owner,
+        action: AuditAction::OwnershipTransferCancelled,
+        blacklisted: false,
+    });
+
+    Ok(())
+}
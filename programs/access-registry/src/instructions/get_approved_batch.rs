@@ -1,19 +1,72 @@
 use anchor_lang::prelude::*;
 
-use crate::{GetApprovedBatch, AccessRegistryError, MAX_BATCH_SIZE};
+use crate::instructions::is_approved::evaluate_approval;
+use crate::return_data::{packed_bool_vec_len, set_return_data_checked, MAX_RETURN_DATA_LEN};
+use crate::{AccessRegistryError, GetApprovedBatch, MAX_BATCH_SIZE};
 
-pub fn handler(_ctx: Context<GetApprovedBatch>, accounts: Vec<Pubkey>) -> Result<()> {
+pub fn handler(ctx: Context<GetApprovedBatch>, accounts: Vec<Pubkey>) -> Result<()> {
     // Validate batch size
     require!(
         !accounts.is_empty() && accounts.len() <= MAX_BATCH_SIZE,
         AccessRegistryError::InvalidBatchSize
     );
+    require!(
+        packed_bool_vec_len(accounts.len()) <= MAX_RETURN_DATA_LEN,
+        AccessRegistryError::ReturnDataTooLarge
+    );
+
+    let registry = &ctx.accounts.registry;
+    let num_oracles = registry.oracles.len();
+    // Per address: sanctions_account, (oracle_program, sanctions_account) *
+    // num_oracles, allowlist_entry, blacklist_entry.
+    let accounts_per_address = 3 + 2 * num_oracles;
+    require!(
+        ctx.remaining_accounts.len() == accounts.len() * accounts_per_address,
+        AccessRegistryError::ArrayLengthMismatch
+    );
+
+    let chainalysis_oracle = ctx.accounts.chainalysis_oracle.to_account_info();
+    let mut results = Vec::with_capacity(accounts.len());
+
+    for (address, entry_accounts) in accounts
+        .iter()
+        .zip(ctx.remaining_accounts.chunks(accounts_per_address))
+    {
+        let sanctions_account = &entry_accounts[0];
+        let quorum_accounts = &entry_accounts[1..1 + 2 * num_oracles];
+        let allowlist_entry = &entry_accounts[1 + 2 * num_oracles];
+        let blacklist_entry = &entry_accounts[2 + 2 * num_oracles];
+
+        let (expected_allowlist, _) =
+            Pubkey::find_program_address(&[b"allowlist", address.as_ref()], ctx.program_id);
+        require_keys_eq!(
+            *allowlist_entry.key,
+            expected_allowlist,
+            AccessRegistryError::InvalidAllowlistEntry
+        );
+
+        let (expected_blacklist, _) =
+            Pubkey::find_program_address(&[b"blacklist", address.as_ref()], ctx.program_id);
+        require_keys_eq!(
+            *blacklist_entry.key,
+            expected_blacklist,
+            AccessRegistryError::InvalidBlacklistEntry
+        );
 
-    msg!("get_approved_batch for {} addresses", accounts.len());
-    msg!("Full implementation pending - need to return results");
+        let approved = evaluate_approval(
+            registry,
+            address,
+            &chainalysis_oracle,
+            sanctions_account,
+            quorum_accounts,
+            allowlist_entry,
+            blacklist_entry,
+        )?;
+        results.push(approved);
+    }
 
-    // TODO: Implement full approval checks with results
-    // For now, this is a stub that validates input
+    msg!("get_approved_batch: checked {} addresses", accounts.len());
+    set_return_data_checked(&results)?;
 
     Ok(())
 }
@@ -1,12 +1,15 @@
 use anchor_lang::prelude::*;
 
-use crate::{Initialize, AccessRegistryError, MAX_BATCH_SIZE};
+use crate::instructions::set_blacklisted_batch::create_blacklist_entry;
+use crate::{AccessRegistryError, AuditAction, AuditLogEntry, Initialize, MAX_BATCH_SIZE};
 
 pub fn handler(
     ctx: Context<Initialize>,
     chainalysis_oracle: Pubkey,
     pool_factory_owner: Pubkey,
     initial_blacklist: Vec<Pubkey>,
+    transfer_delay_seconds: i64,
+    recovery_authority: Pubkey,
 ) -> Result<()> {
     let registry = &mut ctx.accounts.registry;
 
@@ -16,14 +19,33 @@ pub fn handler(
         AccessRegistryError::InvalidPoolFactoryAddress
     );
 
+    require!(
+        transfer_delay_seconds >= 0,
+        AccessRegistryError::InvalidTransferDelay
+    );
+
     // Initialize registry
     registry.owner = ctx.accounts.payer.key();
     registry.pending_owner = Pubkey::default();
     registry.chainalysis_oracle = chainalysis_oracle;
     registry.pool_factory_owner = pool_factory_owner;
     registry.blacklist_count = 0;
+    registry.whitelist = Vec::new();
+    registry.oracles = Vec::new();
+    registry.min_quorum = 0;
+    registry.transfer_initiated_at = 0;
+    registry.transfer_delay = transfer_delay_seconds;
+    registry.time_offset = 0;
+    registry.recovery_authority = recovery_authority;
+    registry.paused = false;
     registry.bump = ctx.bumps.registry;
 
+    let audit_log = &mut ctx.accounts.audit_log;
+    audit_log.head = 0;
+    audit_log.count = 0;
+    audit_log.entries = [AuditLogEntry::default(); crate::AUDIT_LOG_CAPACITY];
+    audit_log.bump = ctx.bumps.audit_log;
+
     msg!("AccessRegistry initialized");
     msg!("Owner: {}", registry.owner);
     msg!("Chainalysis Oracle: {}", registry.chainalysis_oracle);
@@ -35,6 +57,10 @@ pub fn handler(
             initial_blacklist.len() <= MAX_BATCH_SIZE,
             AccessRegistryError::InvalidBatchSize
         );
+        require!(
+            ctx.remaining_accounts.len() == initial_blacklist.len(),
+            AccessRegistryError::ArrayLengthMismatch
+        );
 
         // Validate no special addresses in initial blacklist
         for address in &initial_blacklist {
@@ -44,8 +70,39 @@ pub fn handler(
         }
 
         msg!("Pre-populating blacklist with {} addresses", initial_blacklist.len());
-        // Note: In full implementation, would create BlacklistEntry PDAs here
-        // For now, just set the count
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        let payer = ctx.accounts.payer.to_account_info();
+        let system_program = ctx.accounts.system_program.to_account_info();
+
+        for (address, entry_account) in initial_blacklist.iter().zip(ctx.remaining_accounts.iter()) {
+            let (expected_key, bump) =
+                Pubkey::find_program_address(&[b"blacklist", address.as_ref()], ctx.program_id);
+            require_keys_eq!(
+                *entry_account.key,
+                expected_key,
+                AccessRegistryError::InvalidBlacklistEntry
+            );
+
+            create_blacklist_entry(
+                entry_account,
+                &payer,
+                &system_program,
+                ctx.program_id,
+                address,
+                bump,
+                timestamp,
+            )?;
+
+            audit_log.push(AuditLogEntry {
+                timestamp,
+                actor: registry.owner,
+                target: *address,
+                action: AuditAction::Blacklist,
+                blacklisted: true,
+            });
+        }
+
         registry.blacklist_count = initial_blacklist.len() as u32;
     }
 
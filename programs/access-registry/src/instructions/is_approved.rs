@@ -1,31 +1,124 @@
 use anchor_lang::prelude::*;
 
-use crate::IsApproved;
+use crate::return_data::set_return_data_checked;
+use crate::{oracle, AccessRegistry, AccessRegistryError, AllowlistEntry, BlacklistEntry, IsApproved};
 
 pub fn handler(ctx: Context<IsApproved>, account: Pubkey) -> Result<()> {
-    let registry = &ctx.accounts.registry;
+    let approved = evaluate_approval(
+        &ctx.accounts.registry,
+        &account,
+        &ctx.accounts.chainalysis_oracle.to_account_info(),
+        &ctx.accounts.sanctions_account.to_account_info(),
+        ctx.remaining_accounts,
+        &ctx.accounts.allowlist_entry.to_account_info(),
+        &ctx.accounts.blacklist_entry.to_account_info(),
+    )?;
 
-    // Auto-approve registry owner
-    if registry.is_registry_owner(&account) {
-        msg!("Auto-approved: registry owner");
-        return Ok(());
+    msg!("is_approved({}) = {}", account, approved);
+    set_return_data_checked(&approved)?;
+
+    Ok(())
+}
+
+/// Runs the full approval decision for a single address: special-address
+/// auto-approval, the pause kill-switch, the fail-closed oracle sanction
+/// checks (both the primary Chainalysis oracle and the multi-oracle quorum),
+/// the allowlist, whitelisted trusted programs, then the internal blacklist.
+/// A primary-oracle CPI failure, an oracle or sanctions account that doesn't
+/// match the expected key, a quorum account list of the wrong length, or an
+/// undecodable allowlist/blacklist entry propagates as an error; every
+/// verdict is reported as a boolean so CPI callers can read it from return
+/// data instead of catching a revert.
+///
+/// Shared between `is_approved` and `get_approved_batch` so both entry
+/// points agree on exactly one approval verdict; `get_approved_batch` just
+/// calls this once per address with its own per-address accounts instead of
+/// Anchor-validated ones.
+pub fn evaluate_approval<'info>(
+    registry: &AccessRegistry,
+    account: &Pubkey,
+    chainalysis_oracle: &AccountInfo<'info>,
+    sanctions_account: &AccountInfo<'info>,
+    quorum_accounts: &[AccountInfo<'info>],
+    allowlist_entry: &AccountInfo<'info>,
+    blacklist_entry: &AccountInfo<'info>,
+) -> Result<bool> {
+    if registry.is_special_address(account) {
+        return Ok(true);
     }
 
-    // Auto-approve pool factory owner
-    if registry.is_pool_factory_owner(&account) {
-        msg!("Auto-approved: pool factory owner");
-        return Ok(());
+    // Compliance kill-switch: while paused, every non-special address is
+    // rejected regardless of blacklist/allowlist/oracle state.
+    if registry.paused {
+        return Ok(false);
     }
 
-    // Check if Chainalysis oracle is configured
     if registry.has_oracle() {
-        msg!("Checking Chainalysis oracle for {}", account);
-        // TODO: Implement CPI to oracle
-        msg!("Oracle CPI not yet implemented");
+        require_keys_eq!(
+            *chainalysis_oracle.key,
+            registry.chainalysis_oracle,
+            AccessRegistryError::InvalidOracleAddress
+        );
+
+        let (expected_sanctions, _) =
+            Pubkey::find_program_address(&[b"sanctions", account.as_ref()], chainalysis_oracle.key);
+        require_keys_eq!(
+            *sanctions_account.key,
+            expected_sanctions,
+            AccessRegistryError::InvalidOracleAddress
+        );
+
+        let sanctioned = oracle::is_sanctioned(chainalysis_oracle, sanctions_account, account)?;
+
+        if sanctioned {
+            return Ok(false);
+        }
     }
 
-    // TODO: Check internal blacklist via RPC optimization
-    msg!("is_approved check complete for {}", account);
+    if registry.has_oracles() && registry.min_quorum > 0 {
+        let (votes, _) = oracle::quorum_sanctioned_votes(&registry.oracles, quorum_accounts, account)?;
 
-    Ok(())
+        if votes >= registry.min_quorum {
+            return Ok(false);
+        }
+    }
+
+    // Allowlisted addresses bypass the internal blacklist, but never the
+    // oracle sanction vetoes above — a sanctioned address can't be
+    // force-approved by allowlisting it.
+    if is_allowlisted(allowlist_entry)? {
+        return Ok(true);
+    }
+
+    // Whitelisted trusted programs bypass the internal blacklist, but not
+    // the oracle sanction vetoes above.
+    if registry.is_whitelisted(account) {
+        return Ok(true);
+    }
+
+    Ok(!is_blacklisted(blacklist_entry)?)
+}
+
+/// Reads a (possibly uninitialized) `AllowlistEntry` PDA and reports whether
+/// it marks its address as allowlisted.
+pub fn is_allowlisted(allowlist_entry: &AccountInfo) -> Result<bool> {
+    if allowlist_entry.owner != &crate::ID || allowlist_entry.data_is_empty() {
+        return Ok(false);
+    }
+
+    let data = allowlist_entry.try_borrow_data()?;
+    let entry = AllowlistEntry::try_deserialize(&mut &data[..])?;
+    Ok(entry.allowlisted)
+}
+
+/// Reads a (possibly uninitialized) `BlacklistEntry` PDA and reports whether
+/// it marks its address as blacklisted.
+pub fn is_blacklisted(blacklist_entry: &AccountInfo) -> Result<bool> {
+    if blacklist_entry.owner != &crate::ID || blacklist_entry.data_is_empty() {
+        return Ok(false);
+    }
+
+    let data = blacklist_entry.try_borrow_data()?;
+    let entry = BlacklistEntry::try_deserialize(&mut &data[..])?;
+    Ok(entry.blacklisted)
 }
@@ -1,18 +1,32 @@
 use anchor_lang::prelude::*;
 
-use crate::{IsSanctionedByChainalysis, AccessRegistryError};
+use crate::{oracle, AccessRegistryError, IsSanctionedByChainalysis};
 
-pub fn handler(_ctx: Context<IsSanctionedByChainalysis>, _account: Pubkey) -> Result<()> {
-    // TODO: Implement CPI to Chainalysis oracle
-    // This will require:
-    // 1. Adding oracle accounts to the context
-    // 2. Building CPI context
-    // 3. Calling oracle's is_sanctioned instruction
-    // 4. Handling result with fail-closed error handling
+pub fn handler(ctx: Context<IsSanctionedByChainalysis>, account: Pubkey) -> Result<()> {
+    let registry = &ctx.accounts.registry;
 
-    msg!("is_sanctioned_by_chainalysis not yet implemented");
-    msg!("Will require CPI to Chainalysis oracle program");
+    require!(registry.has_oracles(), AccessRegistryError::InvalidOracleAddress);
 
-    // For now, return an error indicating this is not implemented
-    Err(AccessRegistryError::OracleFailure.into())
+    let (votes, any_reachable) =
+        oracle::quorum_sanctioned_votes(&registry.oracles, ctx.remaining_accounts, &account)?;
+
+    require!(any_reachable, AccessRegistryError::OracleFailure);
+
+    if registry.min_quorum > 0 && votes >= registry.min_quorum {
+        msg!(
+            "{} is sanctioned: {} of {} configured oracles agree",
+            account,
+            votes,
+            registry.oracles.len()
+        );
+        return Err(AccessRegistryError::AddressSanctioned.into());
+    }
+
+    msg!(
+        "{} is not sanctioned: {} of {} configured oracles voted sanctioned",
+        account,
+        votes,
+        registry.oracles.len()
+    );
+    Ok(())
 }
@@ -5,10 +5,23 @@
 // - A handler function implementing the logic
 
 pub mod accept_ownership;
+pub mod add_oracle;
+pub mod cancel_ownership_transfer;
 pub mod get_approved_batch;
 pub mod initialize;
 pub mod is_approved;
 pub mod is_sanctioned_by_chainalysis;
+pub mod pause;
+pub mod recover_ownership;
+pub mod remove_oracle;
+pub mod set_allowlisted;
+pub mod set_allowlisted_batch;
 pub mod set_blacklisted;
 pub mod set_blacklisted_batch;
+pub mod set_quorum;
+#[cfg(feature = "test-time-offset")]
+pub mod set_time_offset;
 pub mod transfer_ownership;
+pub mod unpause;
+pub mod whitelist_add;
+pub mod whitelist_remove;
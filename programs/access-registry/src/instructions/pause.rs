@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+use crate::{AccessRegistryError, Pause};
+
+pub fn handler(ctx: Context<Pause>) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+
+    require!(!registry.paused, AccessRegistryError::AlreadyPaused);
+
+    registry.paused = true;
+
+    msg!("AccessRegistry paused");
+
+    Ok(())
+}
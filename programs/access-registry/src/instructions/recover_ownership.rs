@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+#[cfg(feature = "owner-recovery")]
+use crate::{AuditAction, AuditLogEntry};
+use crate::{AccessRegistryError, RecoverOwnership};
+
+pub fn handler(ctx: Context<RecoverOwnership>, new_owner: Pubkey) -> Result<()> {
+    #[cfg(not(feature = "owner-recovery"))]
+    {
+        let _ = ctx;
+        let _ = new_owner;
+        return Err(AccessRegistryError::FeatureNotEnabled.into());
+    }
+
+    #[cfg(feature = "owner-recovery")]
+    {
+        let registry = &mut ctx.accounts.registry;
+
+        require!(
+            registry.recovery_authority != Pubkey::default()
+                && registry.recovery_authority == ctx.accounts.authority.key(),
+            AccessRegistryError::Unauthorized
+        );
+
+        require!(
+            new_owner != Pubkey::default() && !registry.is_special_address(&new_owner),
+            AccessRegistryError::InvalidPendingOwner
+        );
+
+        registry.owner = new_owner;
+        registry.pending_owner = Pubkey::default();
+        registry.transfer_initiated_at = 0;
+
+        msg!("Ownership recovered: new owner is {}", new_owner);
+
+        ctx.accounts.audit_log.push(AuditLogEntry {
+            timestamp: Clock::get()?.unix_timestamp,
+            actor: ctx.accounts.authority.key(),
+            target: new_owner,
+            action: AuditAction::OwnershipRecovered,
+            blacklisted: false,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::{AccessRegistryError, RemoveOracle};
+
+pub fn handler(ctx: Context<RemoveOracle>, oracle: Pubkey) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+
+    let index = registry
+        .oracles
+        .iter()
+        .position(|&configured| configured == oracle)
+        .ok_or(AccessRegistryError::OracleEntryNotFound)?;
+
+    registry.oracles.remove(index);
+
+    // A quorum above the remaining oracle count can never be met; clamp it
+    // down so the quorum check doesn't silently become unsatisfiable.
+    registry.min_quorum = registry.min_quorum.min(registry.oracles.len() as u8);
+
+    msg!("Removed sanction oracle: {}", oracle);
+
+    Ok(())
+}
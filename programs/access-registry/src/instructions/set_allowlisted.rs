@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{AccessRegistryError, SetAllowlisted};
+
+pub fn handler(ctx: Context<SetAllowlisted>, account: Pubkey, allowlisted: bool) -> Result<()> {
+    let entry = &mut ctx.accounts.allowlist_entry;
+
+    if allowlisted {
+        // Create or update allowlist entry
+        if entry.account != Pubkey::default() && entry.allowlisted {
+            return Err(AccessRegistryError::AlreadyAllowlisted.into());
+        }
+
+        entry.account = account;
+        entry.allowlisted = true;
+        entry.timestamp = Clock::get()?.unix_timestamp;
+        entry.bump = ctx.bumps.allowlist_entry;
+
+        msg!("Allowlisted: {}", account);
+    } else {
+        // Close allowlist entry (remove)
+        if entry.account == Pubkey::default() || !entry.allowlisted {
+            return Err(AccessRegistryError::NotAllowlisted.into());
+        }
+
+        // Close the account and return lamports to the owner
+        ctx.accounts.allowlist_entry.close(ctx.accounts.authority.to_account_info())?;
+
+        msg!("Removed from allowlist: {}", account);
+    }
+
+    Ok(())
+}
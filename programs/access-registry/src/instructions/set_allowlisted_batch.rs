@@ -0,0 +1,140 @@
+use std::io::Write;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::system_instruction;
+
+use crate::{AccessRegistryError, AllowlistEntry, SetAllowlistedBatch, MAX_BATCH_SIZE};
+
+pub fn handler(
+    ctx: Context<SetAllowlistedBatch>,
+    accounts: Vec<Pubkey>,
+    allowlisted: bool,
+) -> Result<()> {
+    // Validate batch size
+    require!(
+        !accounts.is_empty() && accounts.len() <= MAX_BATCH_SIZE,
+        AccessRegistryError::InvalidBatchSize
+    );
+    require!(
+        ctx.remaining_accounts.len() == accounts.len(),
+        AccessRegistryError::ArrayLengthMismatch
+    );
+
+    // Validate no duplicates in the batch
+    for (i, address) in accounts.iter().enumerate() {
+        if accounts[..i].contains(address) {
+            return Err(AccessRegistryError::DuplicateAddressInBatch.into());
+        }
+    }
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    let authority = ctx.accounts.authority.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    for (address, entry_account) in accounts.iter().zip(ctx.remaining_accounts.iter()) {
+        let (expected_key, bump) =
+            Pubkey::find_program_address(&[b"allowlist", address.as_ref()], ctx.program_id);
+        require_keys_eq!(
+            *entry_account.key,
+            expected_key,
+            AccessRegistryError::InvalidAllowlistEntry
+        );
+
+        if allowlisted {
+            create_allowlist_entry(
+                entry_account,
+                &authority,
+                &system_program,
+                ctx.program_id,
+                address,
+                bump,
+                timestamp,
+            )?;
+        } else {
+            close_allowlist_entry(entry_account, &authority)?;
+        }
+    }
+
+    msg!(
+        "Batch allowlist update complete for {} addresses (allowlisted: {})",
+        accounts.len(),
+        allowlisted
+    );
+
+    Ok(())
+}
+
+/// Creates and initializes an `AllowlistEntry` PDA for `address`, failing if
+/// one already exists (i.e. the address is already allowlisted).
+fn create_allowlist_entry<'info>(
+    entry_account: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    program_id: &Pubkey,
+    address: &Pubkey,
+    bump: u8,
+    timestamp: i64,
+) -> Result<()> {
+    require!(
+        entry_account.data_is_empty(),
+        AccessRegistryError::AlreadyAllowlisted
+    );
+
+    let space = 8 + AllowlistEntry::INIT_SPACE;
+    let lamports = Rent::get()?.minimum_balance(space);
+    let seeds: &[&[u8]] = &[b"allowlist", address.as_ref(), &[bump]];
+
+    invoke_signed(
+        &system_instruction::create_account(
+            authority.key,
+            entry_account.key,
+            lamports,
+            space as u64,
+            program_id,
+        ),
+        &[authority.clone(), entry_account.clone(), system_program.clone()],
+        &[seeds],
+    )?;
+
+    let entry = AllowlistEntry {
+        account: *address,
+        allowlisted: true,
+        timestamp,
+        bump,
+    };
+
+    let mut data = entry_account.try_borrow_mut_data()?;
+    let mut cursor: &mut [u8] = &mut data;
+    cursor.write_all(&AllowlistEntry::DISCRIMINATOR)?;
+    entry.serialize(&mut cursor)?;
+
+    Ok(())
+}
+
+/// Closes an existing `AllowlistEntry` PDA for `address` and refunds its
+/// rent to `authority`, failing if it doesn't exist or isn't allowlisted.
+fn close_allowlist_entry<'info>(
+    entry_account: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+) -> Result<()> {
+    require!(
+        !entry_account.data_is_empty(),
+        AccessRegistryError::NotAllowlisted
+    );
+
+    {
+        let data = entry_account.try_borrow_data()?;
+        let entry = AllowlistEntry::try_deserialize(&mut &data[..])?;
+        require!(entry.allowlisted, AccessRegistryError::NotAllowlisted);
+    }
+
+    let refund = entry_account.lamports();
+    **authority.lamports.borrow_mut() += refund;
+    **entry_account.lamports.borrow_mut() = 0;
+
+    let mut data = entry_account.try_borrow_mut_data()?;
+    data.fill(0);
+
+    Ok(())
+}
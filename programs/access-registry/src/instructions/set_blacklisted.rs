@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{SetBlacklisted, AccessRegistryError};
+use crate::{AccessRegistryError, AuditAction, AuditLogEntry, SetBlacklisted};
 
 pub fn handler(ctx: Context<SetBlacklisted>, account: Pubkey, blacklisted: bool) -> Result<()> {
     let registry = &mut ctx.accounts.registry;
@@ -11,6 +11,7 @@ pub fn handler(ctx: Context<SetBlacklisted>, account: Pubkey, blacklisted: bool)
     }
 
     let entry = &mut ctx.accounts.blacklist_entry;
+    let timestamp = Clock::get()?.unix_timestamp;
 
     if blacklisted {
         // Create or update blacklist entry
@@ -20,7 +21,7 @@ pub fn handler(ctx: Context<SetBlacklisted>, account: Pubkey, blacklisted: bool)
 
         entry.account = account;
         entry.blacklisted = true;
-        entry.timestamp = Clock::get()?.unix_timestamp;
+        entry.timestamp = timestamp;
         entry.bump = ctx.bumps.blacklist_entry;
 
         registry.blacklist_count = registry.blacklist_count.saturating_add(1);
@@ -40,5 +41,13 @@ pub fn handler(ctx: Context<SetBlacklisted>, account: Pubkey, blacklisted: bool)
         msg!("Unblacklisted: {}", account);
     }
 
+    ctx.accounts.audit_log.push(AuditLogEntry {
+        timestamp,
+        actor: ctx.accounts.authority.key(),
+        target: account,
+        action: AuditAction::Blacklist,
+        blacklisted,
+    });
+
     Ok(())
 }
@@ -1,36 +1,167 @@
+use std::io::Write;
+
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::system_instruction;
 
-use crate::{SetBlacklistedBatch, AccessRegistryError, MAX_BATCH_SIZE};
+use crate::{
+    AccessRegistryError, AuditAction, AuditLogEntry, BlacklistEntry, SetBlacklistedBatch, MAX_BATCH_SIZE,
+};
 
 pub fn handler(
     ctx: Context<SetBlacklistedBatch>,
     accounts: Vec<Pubkey>,
     blacklisted: bool,
 ) -> Result<()> {
-    let registry = &mut ctx.accounts.registry;
-
     // Validate batch size
     require!(
         !accounts.is_empty() && accounts.len() <= MAX_BATCH_SIZE,
         AccessRegistryError::InvalidBatchSize
     );
+    require!(
+        ctx.remaining_accounts.len() == accounts.len(),
+        AccessRegistryError::ArrayLengthMismatch
+    );
 
-    // Validate no special addresses in batch
-    for address in &accounts {
-        if registry.is_special_address(address) {
+    // Validate no special addresses and no duplicates in the batch
+    for (i, address) in accounts.iter().enumerate() {
+        if ctx.accounts.registry.is_special_address(address) {
             return Err(AccessRegistryError::CannotBlacklistSpecialAddress.into());
         }
+        if accounts[..i].contains(address) {
+            return Err(AccessRegistryError::DuplicateAddressInBatch.into());
+        }
+    }
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    let authority = ctx.accounts.authority.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let mut net_delta: i64 = 0;
+
+    for (address, entry_account) in accounts.iter().zip(ctx.remaining_accounts.iter()) {
+        let (expected_key, bump) =
+            Pubkey::find_program_address(&[b"blacklist", address.as_ref()], ctx.program_id);
+        require_keys_eq!(
+            *entry_account.key,
+            expected_key,
+            AccessRegistryError::InvalidBlacklistEntry
+        );
+
+        if blacklisted {
+            create_blacklist_entry(
+                entry_account,
+                &authority,
+                &system_program,
+                ctx.program_id,
+                address,
+                bump,
+                timestamp,
+            )?;
+            net_delta += 1;
+        } else {
+            close_blacklist_entry(entry_account, &authority)?;
+            net_delta -= 1;
+        }
+    }
+
+    let registry = &mut ctx.accounts.registry;
+    registry.blacklist_count = if net_delta >= 0 {
+        registry.blacklist_count.saturating_add(net_delta as u32)
+    } else {
+        registry.blacklist_count.saturating_sub((-net_delta) as u32)
+    };
+
+    let audit_log = &mut ctx.accounts.audit_log;
+    let actor = authority.key();
+    for address in &accounts {
+        audit_log.push(AuditLogEntry {
+            timestamp,
+            actor,
+            target: *address,
+            action: AuditAction::Blacklist,
+            blacklisted,
+        });
+    }
+
+    msg!(
+        "Batch blacklist update complete for {} addresses (blacklisted: {})",
+        accounts.len(),
+        blacklisted
+    );
+
+    Ok(())
+}
+
+/// Creates and initializes a `BlacklistEntry` PDA for `address`, failing if
+/// one already exists (i.e. the address is already blacklisted).
+pub(crate) fn create_blacklist_entry<'info>(
+    entry_account: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    program_id: &Pubkey,
+    address: &Pubkey,
+    bump: u8,
+    timestamp: i64,
+) -> Result<()> {
+    require!(
+        entry_account.data_is_empty(),
+        AccessRegistryError::AlreadyBlacklisted
+    );
+
+    let space = 8 + BlacklistEntry::INIT_SPACE;
+    let lamports = Rent::get()?.minimum_balance(space);
+    let seeds: &[&[u8]] = &[b"blacklist", address.as_ref(), &[bump]];
+
+    invoke_signed(
+        &system_instruction::create_account(
+            authority.key,
+            entry_account.key,
+            lamports,
+            space as u64,
+            program_id,
+        ),
+        &[authority.clone(), entry_account.clone(), system_program.clone()],
+        &[seeds],
+    )?;
+
+    let entry = BlacklistEntry {
+        account: *address,
+        blacklisted: true,
+        timestamp,
+        bump,
+    };
+
+    let mut data = entry_account.try_borrow_mut_data()?;
+    let mut cursor: &mut [u8] = &mut data;
+    cursor.write_all(&BlacklistEntry::DISCRIMINATOR)?;
+    entry.serialize(&mut cursor)?;
+
+    Ok(())
+}
+
+/// Closes an existing `BlacklistEntry` PDA for `address` and refunds its
+/// rent to `authority`, failing if it doesn't exist or isn't blacklisted.
+fn close_blacklist_entry<'info>(
+    entry_account: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+) -> Result<()> {
+    require!(
+        !entry_account.data_is_empty(),
+        AccessRegistryError::NotBlacklisted
+    );
+
+    {
+        let data = entry_account.try_borrow_data()?;
+        let entry = BlacklistEntry::try_deserialize(&mut &data[..])?;
+        require!(entry.blacklisted, AccessRegistryError::NotBlacklisted);
     }
 
-    msg!("Batch blacklist update for {} addresses (blacklisted: {})", accounts.len(), blacklisted);
-    msg!("set_blacklisted_batch not yet fully implemented");
+    let refund = entry_account.lamports();
+    **authority.lamports.borrow_mut() += refund;
+    **entry_account.lamports.borrow_mut() = 0;
 
-    // TODO: Implement full batch processing
-    // For each address:
-    // 1. Derive BlacklistEntry PDA
-    // 2. Create or close the PDA
-    // 3. Update blacklist_count
-    // All operations must be atomic (revert if any fails)
+    let mut data = entry_account.try_borrow_mut_data()?;
+    data.fill(0);
 
     Ok(())
 }
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use crate::{AccessRegistryError, SetQuorum};
+
+pub fn handler(ctx: Context<SetQuorum>, min_quorum: u8) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+
+    require!(
+        min_quorum as usize <= registry.oracles.len(),
+        AccessRegistryError::InvalidQuorum
+    );
+
+    registry.min_quorum = min_quorum;
+
+    msg!("Sanction oracle quorum set to {}", min_quorum);
+
+    Ok(())
+}
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+use crate::SetTimeOffset;
+
+pub fn handler(ctx: Context<SetTimeOffset>, time_offset: i64) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+
+    registry.time_offset = time_offset;
+
+    msg!("Timelock clock offset set to {} seconds", time_offset);
+
+    Ok(())
+}
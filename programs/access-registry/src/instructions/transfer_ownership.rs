@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{TransferOwnership, AccessRegistryError};
+use crate::{AccessRegistryError, AuditAction, AuditLogEntry, TransferOwnership};
 
 pub fn handler(ctx: Context<TransferOwnership>, pending_owner: Pubkey) -> Result<()> {
     let registry = &mut ctx.accounts.registry;
@@ -18,8 +18,21 @@ pub fn handler(ctx: Context<TransferOwnership>, pending_owner: Pubkey) -> Result
     );
 
     registry.pending_owner = pending_owner;
+    registry.transfer_initiated_at = registry.timelock_now()?;
 
     msg!("Ownership transfer initiated to {}", pending_owner);
+    msg!(
+        "Transfer may be accepted after {} seconds",
+        registry.transfer_delay
+    );
+
+    ctx.accounts.audit_log.push(AuditLogEntry {
+        timestamp: registry.transfer_initiated_at,
+        actor: ctx.accounts.authority.key(),
+        target: pending_owner,
+        action: AuditAction::OwnershipTransferInitiated,
+        blacklisted: false,
+    });
 
     Ok(())
 }
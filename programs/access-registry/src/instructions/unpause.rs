@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+use crate::{AccessRegistryError, Unpause};
+
+pub fn handler(ctx: Context<Unpause>) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+
+    require!(registry.paused, AccessRegistryError::NotPaused);
+
+    registry.paused = false;
+
+    msg!("AccessRegistry unpaused");
+
+    Ok(())
+}
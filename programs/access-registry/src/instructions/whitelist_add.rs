@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::{AccessRegistryError, WhitelistAdd, WhitelistEntry, MAX_WHITELIST_SIZE};
+
+pub fn handler(ctx: Context<WhitelistAdd>, program: Pubkey) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+
+    require!(
+        !registry.is_whitelisted(&program),
+        AccessRegistryError::AlreadyWhitelisted
+    );
+    require!(
+        registry.whitelist.len() < MAX_WHITELIST_SIZE,
+        AccessRegistryError::WhitelistFull
+    );
+
+    registry.whitelist.push(WhitelistEntry { program });
+
+    msg!("Whitelisted program: {}", program);
+
+    Ok(())
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+use crate::{AccessRegistryError, WhitelistRemove};
+
+pub fn handler(ctx: Context<WhitelistRemove>, program: Pubkey) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+
+    let index = registry
+        .whitelist
+        .iter()
+        .position(|entry| entry.program == program)
+        .ok_or(AccessRegistryError::WhitelistEntryNotFound)?;
+
+    registry.whitelist.remove(index);
+
+    msg!("Removed whitelisted program: {}", program);
+
+    Ok(())
+}
@@ -8,16 +8,27 @@
 //
 // Key Features:
 // - Internal blacklist (owner-controlled)
+// - Internal allowlist for pre-cleared addresses (owner-controlled)
 // - Chainalysis Oracle integration (fail-closed design)
-// - Auto-approval for registry owner and pool factory owner
+// - Multi-oracle sanction quorum for resilience against a single oracle outage
+// - Auto-approval for registry owner, pool factory owner, and whitelisted programs
 // - Batch operations (up to 15 addresses)
-// - Two-step ownership transfer
+// - Two-step ownership transfer, timelocked with an on-chain delay and
+//   cancellable by the current owner before it is accepted
 // - Re-initialization attack protection
+// - Append-only ring-buffer audit log of blacklist mutations and
+//   ownership-transfer lifecycle events
+// - Feature-gated emergency owner recovery, for when the two-step transfer
+//   leaves the registry stuck
+// - Global pause switch: an owner-only compliance kill-switch that forces
+//   fail-closed approval for all non-special addresses
 
 declare_id!("25fGver7srxMVBXA8H7eMXMUqXAkxiHLF1w7V91t9Zfw");
 
 pub mod error;
 pub mod instructions;
+pub mod oracle;
+pub mod return_data;
 pub mod state;
 
 use anchor_lang::prelude::*;
@@ -30,6 +41,11 @@ pub use state::*;
 // =============================================================================
 
 /// Accounts for initialize instruction
+///
+/// If `initial_blacklist` is non-empty, each address's `BlacklistEntry` PDA
+/// (seeds `["blacklist", address]`) must be passed in `remaining_accounts`,
+/// in the same order as `initial_blacklist`, exactly as `set_blacklisted_batch`
+/// expects.
 #[derive(Accounts)]
 pub struct Initialize<'info> {
     #[account(
@@ -41,6 +57,15 @@ pub struct Initialize<'info> {
     )]
     pub registry: Account<'info, AccessRegistry>,
 
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + AuditLog::INIT_SPACE,
+        seeds = [b"audit_log"],
+        bump
+    )]
+    pub audit_log: Account<'info, AuditLog>,
+
     #[account(mut)]
     pub payer: Signer<'info>,
 
@@ -66,6 +91,13 @@ pub struct SetBlacklisted<'info> {
     )]
     pub blacklist_entry: Account<'info, BlacklistEntry>,
 
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Account<'info, AuditLog>,
+
     /// CHECK: account is only used as seed
     pub account: UncheckedAccount<'info>,
 
@@ -89,6 +121,62 @@ pub struct SetBlacklistedBatch<'info> {
     pub registry: Account<'info, AccessRegistry>,
 
     #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Account<'info, AuditLog>,
+
+    #[account(
+        mut,
+        constraint = registry.owner == authority.key() @ AccessRegistryError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for set_allowlisted instruction
+#[derive(Accounts)]
+pub struct SetAllowlisted<'info> {
+    #[account(
+        seeds = [b"access_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, AccessRegistry>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + AllowlistEntry::INIT_SPACE,
+        seeds = [b"allowlist", account.key().as_ref()],
+        bump
+    )]
+    pub allowlist_entry: Account<'info, AllowlistEntry>,
+
+    /// CHECK: account is only used as seed
+    pub account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = registry.owner == authority.key() @ AccessRegistryError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for set_allowlisted_batch instruction
+#[derive(Accounts)]
+pub struct SetAllowlistedBatch<'info> {
+    #[account(
+        seeds = [b"access_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, AccessRegistry>,
+
+    #[account(
+        mut,
         constraint = registry.owner == authority.key() @ AccessRegistryError::Unauthorized
     )]
     pub authority: Signer<'info>,
@@ -98,15 +186,48 @@ pub struct SetBlacklistedBatch<'info> {
 
 /// Accounts for is_approved instruction
 #[derive(Accounts)]
+#[instruction(account: Pubkey)]
 pub struct IsApproved<'info> {
     #[account(
         seeds = [b"access_registry"],
         bump = registry.bump
     )]
     pub registry: Account<'info, AccessRegistry>,
+
+    #[account(
+        constraint = chainalysis_oracle.key() == registry.chainalysis_oracle @ AccessRegistryError::InvalidOracleAddress
+    )]
+    /// CHECK: key is checked against registry.chainalysis_oracle above
+    pub chainalysis_oracle: UncheckedAccount<'info>,
+
+    /// CHECK: oracle-owned sanctions PDA, validated by seed derivation
+    #[account(
+        seeds = [b"sanctions", account.as_ref()],
+        bump,
+        seeds::program = chainalysis_oracle.key()
+    )]
+    pub sanctions_account: UncheckedAccount<'info>,
+
+    /// CHECK: internal allowlist PDA for `account`; may not exist yet
+    #[account(
+        seeds = [b"allowlist", account.as_ref()],
+        bump
+    )]
+    pub allowlist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: internal blacklist PDA for `account`; may not exist yet
+    #[account(
+        seeds = [b"blacklist", account.as_ref()],
+        bump
+    )]
+    pub blacklist_entry: UncheckedAccount<'info>,
 }
 
 /// Accounts for is_sanctioned_by_chainalysis instruction
+///
+/// The configured oracle/sanctions-account pairs are passed via
+/// `remaining_accounts`, in the same fixed order as `registry.oracles`, one
+/// `(oracle_program, sanctions_account)` pair per oracle.
 #[derive(Accounts)]
 pub struct IsSanctionedByChainalysis<'info> {
     #[account(
@@ -117,6 +238,12 @@ pub struct IsSanctionedByChainalysis<'info> {
 }
 
 /// Accounts for get_approved_batch instruction
+///
+/// Per address, `remaining_accounts` must hold (in this order): the primary
+/// oracle's `sanctions_account`, one `(oracle_program, sanctions_account)`
+/// pair per entry in `registry.oracles`, the `allowlist_entry`, then the
+/// `blacklist_entry` — the same per-address evidence `is_approved` consults,
+/// so both instructions agree on exactly one approval verdict.
 #[derive(Accounts)]
 pub struct GetApprovedBatch<'info> {
     #[account(
@@ -124,6 +251,12 @@ pub struct GetApprovedBatch<'info> {
         bump = registry.bump
     )]
     pub registry: Account<'info, AccessRegistry>,
+
+    #[account(
+        constraint = chainalysis_oracle.key() == registry.chainalysis_oracle @ AccessRegistryError::InvalidOracleAddress
+    )]
+    /// CHECK: key is checked against registry.chainalysis_oracle above
+    pub chainalysis_oracle: UncheckedAccount<'info>,
 }
 
 /// Accounts for transfer_ownership instruction
@@ -136,6 +269,13 @@ pub struct TransferOwnership<'info> {
     )]
     pub registry: Account<'info, AccessRegistry>,
 
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Account<'info, AuditLog>,
+
     /// CHECK: pending_owner is only stored, not validated beyond being non-zero
     pub pending_owner: UncheckedAccount<'info>,
 
@@ -155,9 +295,196 @@ pub struct AcceptOwnership<'info> {
     )]
     pub registry: Account<'info, AccessRegistry>,
 
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Account<'info, AuditLog>,
+
     pub signer: Signer<'info>,
 }
 
+/// Accounts for whitelist_add instruction
+#[derive(Accounts)]
+pub struct WhitelistAdd<'info> {
+    #[account(
+        mut,
+        seeds = [b"access_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, AccessRegistry>,
+
+    #[account(
+        constraint = registry.owner == authority.key() @ AccessRegistryError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Accounts for whitelist_remove instruction
+#[derive(Accounts)]
+pub struct WhitelistRemove<'info> {
+    #[account(
+        mut,
+        seeds = [b"access_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, AccessRegistry>,
+
+    #[account(
+        constraint = registry.owner == authority.key() @ AccessRegistryError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Accounts for add_oracle instruction
+#[derive(Accounts)]
+pub struct AddOracle<'info> {
+    #[account(
+        mut,
+        seeds = [b"access_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, AccessRegistry>,
+
+    #[account(
+        constraint = registry.owner == authority.key() @ AccessRegistryError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Accounts for remove_oracle instruction
+#[derive(Accounts)]
+pub struct RemoveOracle<'info> {
+    #[account(
+        mut,
+        seeds = [b"access_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, AccessRegistry>,
+
+    #[account(
+        constraint = registry.owner == authority.key() @ AccessRegistryError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Accounts for set_quorum instruction
+#[derive(Accounts)]
+pub struct SetQuorum<'info> {
+    #[account(
+        mut,
+        seeds = [b"access_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, AccessRegistry>,
+
+    #[account(
+        constraint = registry.owner == authority.key() @ AccessRegistryError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Accounts for cancel_ownership_transfer instruction
+#[derive(Accounts)]
+pub struct CancelOwnershipTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [b"access_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, AccessRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Account<'info, AuditLog>,
+
+    #[account(
+        constraint = registry.owner == authority.key() @ AccessRegistryError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Accounts for pause instruction
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    #[account(
+        mut,
+        seeds = [b"access_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, AccessRegistry>,
+
+    #[account(
+        constraint = registry.owner == authority.key() @ AccessRegistryError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Accounts for unpause instruction
+#[derive(Accounts)]
+pub struct Unpause<'info> {
+    #[account(
+        mut,
+        seeds = [b"access_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, AccessRegistry>,
+
+    #[account(
+        constraint = registry.owner == authority.key() @ AccessRegistryError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Accounts for recover_ownership instruction
+///
+/// Always present (not `#[cfg]`-gated) so the instruction's shape in the IDL
+/// is stable across builds regardless of whether the `owner-recovery`
+/// feature is enabled; the authorization check is instead performed inside
+/// the handler, conditional on that feature.
+#[derive(Accounts)]
+pub struct RecoverOwnership<'info> {
+    #[account(
+        mut,
+        seeds = [b"access_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, AccessRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Account<'info, AuditLog>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Accounts for set_time_offset instruction
+/// Only compiled in when the `test-time-offset` feature is enabled, so
+/// integration tests can fast-forward the ownership-transfer timelock
+/// deterministically instead of sleeping in wall-clock time.
+#[cfg(feature = "test-time-offset")]
+#[derive(Accounts)]
+pub struct SetTimeOffset<'info> {
+    #[account(
+        mut,
+        seeds = [b"access_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, AccessRegistry>,
+
+    #[account(
+        constraint = registry.owner == authority.key() @ AccessRegistryError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
 // =============================================================================
 // PROGRAM
 // =============================================================================
@@ -174,7 +501,15 @@ pub mod access_registry {
     /// # Arguments
     /// * `chainalysis_oracle` - Chainalysis Oracle program ID (use default() to disable)
     /// * `pool_factory_owner` - PoolFactory owner address (auto-approved)
-    /// * `initial_blacklist` - Optional array of addresses to blacklist on initialization
+    /// * `initial_blacklist` - Optional array of addresses to blacklist on initialization;
+    ///   each address's `BlacklistEntry` PDA must be passed in `remaining_accounts`,
+    ///   in the same order
+    /// * `transfer_delay_seconds` - Cooldown, in seconds, `accept_ownership` must wait
+    ///   after `transfer_ownership` before it can succeed (0 disables the timelock)
+    /// * `recovery_authority` - Pre-designated authority allowed to call
+    ///   `recover_ownership` (use default() to disable recovery). Always
+    ///   stored regardless of whether the `owner-recovery` feature is enabled,
+    ///   so this instruction's signature is stable across builds.
     ///
     /// # Security
     /// This function can only be called once. Subsequent calls will fail with
@@ -184,8 +519,17 @@ pub mod access_registry {
         chainalysis_oracle: Pubkey,
         pool_factory_owner: Pubkey,
         initial_blacklist: Vec<Pubkey>,
+        transfer_delay_seconds: i64,
+        recovery_authority: Pubkey,
     ) -> Result<()> {
-        instructions::initialize::handler(ctx, chainalysis_oracle, pool_factory_owner, initial_blacklist)
+        instructions::initialize::handler(
+            ctx,
+            chainalysis_oracle,
+            pool_factory_owner,
+            initial_blacklist,
+            transfer_delay_seconds,
+            recovery_authority,
+        )
     }
 
     /// Set blacklist status for a single address
@@ -210,8 +554,11 @@ pub mod access_registry {
 
     /// Set blacklist status for multiple addresses
     ///
-    /// Atomically updates blacklist status for up to 15 addresses.
-    /// If any operation fails, the entire transaction is reverted.
+    /// Atomically updates blacklist status for up to 15 addresses. Each
+    /// address's `BlacklistEntry` PDA (seeds `["blacklist", address]`) must
+    /// be passed in `remaining_accounts`, in the same order as `accounts`.
+    /// If any single address fails validation or its PDA create/close
+    /// errors, the whole instruction reverts, so partial batches never land.
     ///
     /// # Arguments
     /// * `accounts` - Array of addresses to update
@@ -221,6 +568,7 @@ pub mod access_registry {
     /// - Only the registry owner can call this function
     /// - Cannot blacklist registry owner or pool factory owner (transaction fails)
     /// - Maximum batch size is 15 addresses
+    /// - Rejects duplicate addresses within the same batch
     pub fn set_blacklisted_batch(
         ctx: Context<SetBlacklistedBatch>,
         accounts: Vec<Pubkey>,
@@ -229,17 +577,76 @@ pub mod access_registry {
         instructions::set_blacklisted_batch::handler(ctx, accounts, blacklisted)
     }
 
+    /// Set allowlist status for a single address
+    ///
+    /// Creates or closes an `AllowlistEntry` PDA for the specified address.
+    /// Allowlisted addresses are auto-approved by `is_approved`, after the
+    /// oracle sanction checks — an address can never be force-approved past
+    /// a sanction verdict by allowlisting it.
+    ///
+    /// # Arguments
+    /// * `account` - The address to allowlist or un-allowlist
+    /// * `allowlisted` - true to allowlist, false to remove
+    ///
+    /// # Security
+    /// - Only the registry owner can call this function
+    /// - When removed, the PDA is closed and rent is returned to the owner
+    pub fn set_allowlisted(
+        ctx: Context<SetAllowlisted>,
+        account: Pubkey,
+        allowlisted: bool,
+    ) -> Result<()> {
+        instructions::set_allowlisted::handler(ctx, account, allowlisted)
+    }
+
+    /// Set allowlist status for multiple addresses
+    ///
+    /// Atomically updates allowlist status for up to 15 addresses. Each
+    /// address's `AllowlistEntry` PDA (seeds `["allowlist", address]`) must
+    /// be passed in `remaining_accounts`, in the same order as `accounts`.
+    /// If any single address fails validation or its PDA create/close
+    /// errors, the whole instruction reverts, so partial batches never land.
+    ///
+    /// # Arguments
+    /// * `accounts` - Array of addresses to update
+    /// * `allowlisted` - true to allowlist, false to remove all
+    ///
+    /// # Security
+    /// - Only the registry owner can call this function
+    /// - Maximum batch size is 15 addresses
+    /// - Rejects duplicate addresses within the same batch
+    pub fn set_allowlisted_batch(
+        ctx: Context<SetAllowlistedBatch>,
+        accounts: Vec<Pubkey>,
+        allowlisted: bool,
+    ) -> Result<()> {
+        instructions::set_allowlisted_batch::handler(ctx, accounts, allowlisted)
+    }
+
     /// Check if an address is approved
     ///
-    /// Returns true if the address passes all checks:
+    /// Evaluates, in order:
     /// 1. Auto-approved if registry owner
     /// 2. Auto-approved if pool factory owner
-    /// 3. Rejected if internally blacklisted
-    /// 4. Rejected if Chainalysis Oracle returns sanctioned (fail-closed)
+    /// 3. Rejected if the registry is paused (compliance kill-switch)
+    /// 4. Rejected if the primary Chainalysis Oracle returns sanctioned (fail-closed)
+    /// 5. Rejected if the multi-oracle quorum reaches `min_quorum` sanctioned votes
+    /// 6. Auto-approved if allowlisted (never overrides a sanction veto above)
+    /// 7. Auto-approved if a whitelisted trusted program
+    /// 8. Rejected if internally blacklisted
+    ///
+    /// When `oracles` is non-empty, pass one `(oracle_program, sanctions_account)`
+    /// pair per configured oracle via `remaining_accounts`, in list order.
     ///
     /// # Arguments
     /// * `account` - The address to check
     ///
+    /// # Returns
+    /// The instruction succeeds (barring an oracle CPI failure or invalid
+    /// oracle/sanctions accounts) and writes a single boolean byte via
+    /// `set_return_data` so CPI callers can read the verdict instead of
+    /// catching a revert.
+    ///
     /// # Note
     /// This is an on-chain instruction that costs compute units.
     /// For optimization, clients should first check internal blacklist via RPC
@@ -251,16 +658,25 @@ pub mod access_registry {
         instructions::is_approved::handler(ctx, account)
     }
 
-    /// Check if an address is sanctioned by Chainalysis Oracle only
+    /// Check if an address is sanctioned, aggregating every configured
+    /// compliance oracle
     ///
-    /// This function only checks the Chainalysis Oracle, skipping internal
-    /// blacklist and auto-approval logic.
+    /// CPIs each oracle in `registry.oracles` (in order) and counts how many
+    /// report the address sanctioned. Reverts with `AddressSanctioned` once
+    /// that count reaches `min_quorum`; a `min_quorum` of zero never reverts
+    /// with `AddressSanctioned`. Skips internal blacklist and
+    /// auto-approval logic entirely — this checks oracle sanction status
+    /// only.
     ///
     /// # Arguments
     /// * `account` - The address to check
     ///
     /// # Fail-Closed
-    /// If the oracle call fails for any reason, this function reverts.
+    /// An oracle whose CPI errors or whose account doesn't match the
+    /// expected PDA counts as a "sanctioned" vote. At least one oracle must
+    /// be configured (else this function reverts with `InvalidOracleAddress`)
+    /// and actually reachable (else it reverts with `OracleFailure`) rather
+    /// than silently approving.
     pub fn is_sanctioned_by_chainalysis(
         ctx: Context<IsSanctionedByChainalysis>,
         account: Pubkey,
@@ -270,13 +686,19 @@ pub mod access_registry {
 
     /// Check approval status for multiple addresses
     ///
-    /// Returns approval status for up to 15 addresses in a single call.
+    /// Returns approval status for up to 15 addresses in a single call,
+    /// running the exact same approval decision as `is_approved` for each
+    /// one (special-address auto-approval, the pause kill-switch, both
+    /// oracle sanction checks, the allowlist, the whitelist, then the
+    /// internal blacklist) so the two instructions can never disagree. See
+    /// `GetApprovedBatch` for the required `remaining_accounts` layout.
     ///
     /// # Arguments
     /// * `accounts` - Array of addresses to check
     ///
     /// # Returns
-    /// Writes results to the results account (one bool per input address)
+    /// Writes a Borsh-encoded `Vec<bool>` via `set_return_data`, aligned to
+    /// `accounts` order.
     pub fn get_approved_batch(
         ctx: Context<GetApprovedBatch>,
         accounts: Vec<Pubkey>,
@@ -286,7 +708,8 @@ pub mod access_registry {
 
     /// Initiate ownership transfer
     ///
-    /// Sets the pending_owner field. The pending owner must call
+    /// Sets the pending_owner field and starts the on-chain timelock. The
+    /// pending owner must wait at least `transfer_delay` seconds, then call
     /// accept_ownership to complete the transfer.
     ///
     /// # Arguments
@@ -304,8 +727,145 @@ pub mod access_registry {
     /// Accept ownership transfer
     ///
     /// Completes the ownership transfer initiated by transfer_ownership.
-    /// Only the pending owner can call this function.
+    /// Only the pending owner can call this function, and only once
+    /// `transfer_delay` seconds have elapsed since it was initiated.
     pub fn accept_ownership(ctx: Context<AcceptOwnership>) -> Result<()> {
         instructions::accept_ownership::handler(ctx)
     }
+
+    /// Cancel a pending ownership transfer
+    ///
+    /// Clears `pending_owner` and resets the timelock, without requiring the
+    /// delay to have elapsed. Lets the current owner back out of a transfer
+    /// initiated in error, or in response to a compromised pending owner,
+    /// before it can be accepted.
+    ///
+    /// # Security
+    /// Only the current owner can call this function.
+    pub fn cancel_ownership_transfer(ctx: Context<CancelOwnershipTransfer>) -> Result<()> {
+        instructions::cancel_ownership_transfer::handler(ctx)
+    }
+
+    /// Add a trusted program to the whitelist
+    ///
+    /// Whitelisted programs are auto-approved by `is_approved`, bypassing
+    /// the internal blacklist, so authorized pool/router programs beyond
+    /// `pool_factory_owner` can be granted access without being special-cased
+    /// in the registry's core fields.
+    ///
+    /// # Security
+    /// Only the registry owner can call this function. Capped at
+    /// `MAX_WHITELIST_SIZE` entries.
+    pub fn whitelist_add(ctx: Context<WhitelistAdd>, program: Pubkey) -> Result<()> {
+        instructions::whitelist_add::handler(ctx, program)
+    }
+
+    /// Remove a trusted program from the whitelist
+    ///
+    /// # Security
+    /// Only the registry owner can call this function.
+    pub fn whitelist_remove(ctx: Context<WhitelistRemove>, program: Pubkey) -> Result<()> {
+        instructions::whitelist_remove::handler(ctx, program)
+    }
+
+    /// Add a sanction oracle to the multi-oracle quorum
+    ///
+    /// `is_approved` and `is_sanctioned_by_chainalysis` CPI every configured
+    /// oracle and reject an address once at least `min_quorum` of them
+    /// report it sanctioned, removing the single point of failure of
+    /// relying on one oracle. Use `set_quorum` to configure `min_quorum`.
+    ///
+    /// # Arguments
+    /// * `oracle` - Oracle program ID to add
+    ///
+    /// # Security
+    /// Only the registry owner can call this function. Capped at `MAX_ORACLES` entries.
+    pub fn add_oracle(ctx: Context<AddOracle>, oracle: Pubkey) -> Result<()> {
+        instructions::add_oracle::handler(ctx, oracle)
+    }
+
+    /// Remove a sanction oracle from the multi-oracle quorum
+    ///
+    /// # Security
+    /// Only the registry owner can call this function.
+    pub fn remove_oracle(ctx: Context<RemoveOracle>, oracle: Pubkey) -> Result<()> {
+        instructions::remove_oracle::handler(ctx, oracle)
+    }
+
+    /// Set the sanction quorum threshold
+    ///
+    /// Sets the minimum number of "sanctioned" verdicts, among the
+    /// configured `oracles`, required to reject an address in
+    /// `is_approved` and `is_sanctioned_by_chainalysis`.
+    ///
+    /// # Arguments
+    /// * `min_quorum` - Minimum number of "sanctioned" verdicts required to reject;
+    ///   0 disables the quorum check
+    ///
+    /// # Security
+    /// Only the registry owner can call this function. Cannot exceed the
+    /// number of configured oracles.
+    pub fn set_quorum(ctx: Context<SetQuorum>, min_quorum: u8) -> Result<()> {
+        instructions::set_quorum::handler(ctx, min_quorum)
+    }
+
+    /// Pause the registry
+    ///
+    /// Compliance kill-switch: while paused, `is_approved` and
+    /// `get_approved_batch` reject every address except the registry owner
+    /// and pool factory owner, regardless of blacklist, allowlist, or oracle
+    /// state. Lets operators instantly halt interactions — e.g. during an
+    /// active sanctions investigation or a suspected oracle compromise —
+    /// without having to blacklist addresses one by one.
+    ///
+    /// # Security
+    /// Only the registry owner can call this function.
+    pub fn pause(ctx: Context<Pause>) -> Result<()> {
+        instructions::pause::handler(ctx)
+    }
+
+    /// Unpause the registry
+    ///
+    /// Restores normal approval evaluation.
+    ///
+    /// # Security
+    /// Only the registry owner can call this function.
+    pub fn unpause(ctx: Context<Unpause>) -> Result<()> {
+        instructions::unpause::handler(ctx)
+    }
+
+    /// Recover a stuck registry by force-resetting its owner
+    ///
+    /// This instruction always exists so the IDL is stable across builds,
+    /// but only takes effect when the `owner-recovery` feature is enabled —
+    /// otherwise it immediately returns `AccessRegistryError::FeatureNotEnabled`.
+    /// When enabled, lets the pre-designated `recovery_authority` atomically
+    /// set `owner` to `new_owner` and clear any pending transfer, for when
+    /// the two-step transfer leaves the registry stuck (e.g. `pending_owner`
+    /// key lost, or the current owner key compromised). Subject to the same
+    /// special-address validation as `transfer_ownership`; does not weaken
+    /// or bypass that normal transfer path.
+    ///
+    /// # Arguments
+    /// * `new_owner` - The address to force-install as the registry owner
+    ///
+    /// # Security
+    /// Only `registry.recovery_authority` can call this function, and only
+    /// when the `owner-recovery` feature is compiled in.
+    pub fn recover_ownership(ctx: Context<RecoverOwnership>, new_owner: Pubkey) -> Result<()> {
+        instructions::recover_ownership::handler(ctx, new_owner)
+    }
+
+    /// Set the ownership-transfer timelock clock offset
+    ///
+    /// Only compiled in when the `test-time-offset` feature is enabled. Lets
+    /// integration tests fast-forward past `transfer_delay` deterministically
+    /// instead of sleeping in wall-clock time; absent from production builds.
+    ///
+    /// # Security
+    /// Only the current owner can call this function.
+    #[cfg(feature = "test-time-offset")]
+    pub fn set_time_offset(ctx: Context<SetTimeOffset>, time_offset: i64) -> Result<()> {
+        instructions::set_time_offset::handler(ctx, time_offset)
+    }
 }
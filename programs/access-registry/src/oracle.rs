@@ -0,0 +1,122 @@
+// Chainalysis (or compatible) sanctions oracle CPI
+//
+// We don't depend on the oracle's crate directly, so the instruction is
+// built by hand the same way Anchor's generated clients do: an 8-byte
+// sighash discriminator followed by Borsh-encoded arguments. The oracle
+// returns its verdict via `set_return_data`/`get_return_data` as a single
+// boolean byte.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::{get_return_data, invoke};
+
+use crate::AccessRegistryError;
+
+/// Sighash of the oracle's `is_sanctioned` instruction, computed the same
+/// way `#[program]` derives discriminators: the first 8 bytes of
+/// `sha256("global:is_sanctioned")`.
+fn is_sanctioned_discriminator() -> [u8; 8] {
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash(b"global:is_sanctioned").to_bytes()[..8]);
+    discriminator
+}
+
+/// CPIs into the configured sanctions oracle and reports whether `account`
+/// is sanctioned.
+///
+/// # Fail-Closed
+/// Any CPI error, a return buffer that isn't tagged to `oracle_program`, or
+/// a status byte that can't be decoded all surface as
+/// `AccessRegistryError::OracleFailure` instead of silently approving.
+pub fn is_sanctioned<'info>(
+    oracle_program: &AccountInfo<'info>,
+    sanctions_account: &AccountInfo<'info>,
+    account: &Pubkey,
+) -> Result<bool> {
+    let mut data = is_sanctioned_discriminator().to_vec();
+    data.extend_from_slice(&account.to_bytes());
+
+    let ix = Instruction {
+        program_id: *oracle_program.key,
+        accounts: vec![AccountMeta::new_readonly(*sanctions_account.key, false)],
+        data,
+    };
+
+    invoke(&ix, &[sanctions_account.clone(), oracle_program.clone()]).map_err(|_| {
+        msg!("Chainalysis oracle CPI failed; failing closed");
+        error!(AccessRegistryError::OracleFailure)
+    })?;
+
+    let (program_id, return_data) = get_return_data().ok_or_else(|| {
+        msg!("Chainalysis oracle returned no data; failing closed");
+        error!(AccessRegistryError::OracleFailure)
+    })?;
+
+    require_keys_eq!(program_id, *oracle_program.key, AccessRegistryError::OracleFailure);
+
+    match return_data.first() {
+        Some(&status) => Ok(status != 0),
+        None => {
+            msg!("Chainalysis oracle returned an empty status; failing closed");
+            Err(AccessRegistryError::OracleFailure.into())
+        }
+    }
+}
+
+/// Tallies "sanctioned" votes across a multi-oracle quorum.
+///
+/// `remaining_accounts` must contain one `(oracle_program, sanctions_account)`
+/// pair per entry in `oracles`, in the same order. Unlike [`is_sanctioned`],
+/// a single oracle failing (wrong account, CPI error, undecodable status)
+/// does not abort the whole check — fail-closed here means that oracle's
+/// vote is conservatively counted as "sanctioned" rather than dropped, so
+/// one outage can't silently swing the quorum toward approval.
+///
+/// Returns `(sanctioned_votes, any_oracle_reachable)`. The second value lets
+/// callers that rely solely on the oracle quorum (unlike `is_approved`,
+/// which also has the internal blacklist as a backstop) insist that at
+/// least one oracle actually answered before trusting the vote count.
+pub fn quorum_sanctioned_votes<'info>(
+    oracles: &[Pubkey],
+    remaining_accounts: &[AccountInfo<'info>],
+    account: &Pubkey,
+) -> Result<(u8, bool)> {
+    require!(
+        remaining_accounts.len() == oracles.len() * 2,
+        AccessRegistryError::OracleAccountsMismatch
+    );
+
+    let mut sanctioned_votes: u8 = 0;
+    let mut any_reachable = false;
+
+    for (i, oracle_key) in oracles.iter().enumerate() {
+        let oracle_program = &remaining_accounts[i * 2];
+        let sanctions_account = &remaining_accounts[i * 2 + 1];
+
+        let (expected_sanctions, _) =
+            Pubkey::find_program_address(&[b"sanctions", account.as_ref()], oracle_key);
+
+        let vote = if oracle_program.key != oracle_key || sanctions_account.key != &expected_sanctions {
+            msg!("Oracle {} accounts mismatch; vote inconclusive, failing closed", oracle_key);
+            true
+        } else {
+            match is_sanctioned(oracle_program, sanctions_account, account) {
+                Ok(result) => {
+                    any_reachable = true;
+                    result
+                }
+                Err(_) => {
+                    msg!("Oracle {} vote inconclusive (CPI failed); failing closed", oracle_key);
+                    true
+                }
+            }
+        };
+
+        if vote {
+            sanctioned_votes += 1;
+        }
+    }
+
+    Ok((sanctioned_votes, any_reachable))
+}
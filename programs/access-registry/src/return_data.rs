@@ -0,0 +1,35 @@
+// Helpers for communicating instruction verdicts to CPI callers.
+//
+// `is_approved` and `get_approved_batch` succeed even when an address is
+// rejected — the verdict is written to Solana's return-data buffer via
+// `set_return_data` rather than signalled through instruction failure, so
+// CPI callers can branch on the result instead of having to catch an error.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+
+/// Hard cap enforced by the runtime on `sol_set_return_data`.
+pub const MAX_RETURN_DATA_LEN: usize = 1024;
+
+/// Borsh-encoded length, in bytes, of a `Vec<bool>` with `len` elements:
+/// a 4-byte little-endian length prefix followed by one byte per entry.
+pub fn packed_bool_vec_len(len: usize) -> usize {
+    4 + len
+}
+
+/// Borsh-serializes `value` and writes it to the return-data buffer,
+/// rejecting payloads that would exceed [`MAX_RETURN_DATA_LEN`].
+///
+/// # Return Layout
+/// * `is_approved` returns a single byte: `1` for approved, `0` otherwise.
+/// * `get_approved_batch` returns a `Vec<bool>` aligned to the input
+///   `accounts` order (one byte per address, per [`packed_bool_vec_len`]).
+pub fn set_return_data_checked(value: &impl AnchorSerialize) -> Result<()> {
+    let bytes = value.try_to_vec()?;
+    require!(
+        bytes.len() <= MAX_RETURN_DATA_LEN,
+        crate::AccessRegistryError::ReturnDataTooLarge
+    );
+    set_return_data(&bytes);
+    Ok(())
+}
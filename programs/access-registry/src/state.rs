@@ -4,6 +4,12 @@ use anchor_lang::prelude::*;
 /// Solana transaction size limits constrain this to ~15 addresses
 pub const MAX_BATCH_SIZE: usize = 15;
 
+/// Maximum number of trusted programs the whitelist can hold
+pub const MAX_WHITELIST_SIZE: usize = 10;
+
+/// Maximum number of sanction oracles the multi-oracle quorum can hold
+pub const MAX_ORACLES: usize = 8;
+
 /// Main Access Registry Account
 ///
 /// This PDA stores the registry configuration and is the central authority
@@ -19,8 +25,16 @@ pub const MAX_BATCH_SIZE: usize = 15;
 /// - chainalysis_oracle: 32 bytes
 /// - pool_factory_owner: 32 bytes
 /// - blacklist_count: 4 bytes
+/// - whitelist: 4 + MAX_WHITELIST_SIZE * 32 bytes
+/// - oracles: 4 + MAX_ORACLES * 32 bytes
+/// - min_quorum: 1 byte
+/// - transfer_initiated_at: 8 bytes
+/// - transfer_delay: 8 bytes
+/// - time_offset: 8 bytes
+/// - recovery_authority: 32 bytes
+/// - paused: 1 byte
 /// - bump: 1 byte
-/// - Total: 141 bytes
+/// - Total: 199 + 4 + MAX_WHITELIST_SIZE * 32 + 4 + MAX_ORACLES * 32 bytes
 #[account]
 #[derive(InitSpace)]
 pub struct AccessRegistry {
@@ -45,6 +59,47 @@ pub struct AccessRegistry {
     /// Used for tracking and validation
     pub blacklist_count: u32,
 
+    /// Trusted programs that are auto-approved alongside the registry and
+    /// pool factory owners, e.g. authorized pool/router programs
+    #[max_len(MAX_WHITELIST_SIZE)]
+    pub whitelist: Vec<WhitelistEntry>,
+
+    /// Multi-oracle quorum: configured sanction oracle program IDs.
+    /// Checked in addition to (not instead of) `chainalysis_oracle`.
+    #[max_len(MAX_ORACLES)]
+    pub oracles: Vec<Pubkey>,
+
+    /// Minimum number of "sanctioned" verdicts among `oracles` required to
+    /// reject an address. Ignored while `oracles` is empty or while it is
+    /// zero.
+    pub min_quorum: u8,
+
+    /// Unix timestamp at which the current ownership transfer was initiated.
+    /// Zero when there is no transfer in progress.
+    pub transfer_initiated_at: i64,
+
+    /// Cooldown, in seconds, that must elapse after `transfer_initiated_at`
+    /// before `accept_ownership` will succeed
+    pub transfer_delay: i64,
+
+    /// Signed offset applied on top of `Clock::get()` when evaluating the
+    /// ownership-transfer timelock. Always zero outside of the
+    /// `test-time-offset` feature, which lets integration tests fast-forward
+    /// the timelock deterministically instead of sleeping in wall-clock time.
+    pub time_offset: i64,
+
+    /// Pre-designated authority allowed to call `recover_ownership`. Set at
+    /// `initialize`; `Pubkey::default()` disables recovery even when the
+    /// `owner-recovery` feature is compiled in. Always stored so the
+    /// `initialize` instruction's signature is stable across builds.
+    pub recovery_authority: Pubkey,
+
+    /// Compliance kill-switch. While true, `is_approved` and
+    /// `get_approved_batch` reject every address except the registry owner
+    /// and pool factory owner, regardless of blacklist/allowlist/oracle
+    /// state, so admins can still operate and unpause.
+    pub paused: bool,
+
     /// PDA bump for this account
     /// Used for validation and signing
     pub bump: u8,
@@ -84,6 +139,156 @@ pub struct BlacklistEntry {
     pub bump: u8,
 }
 
+/// Per-Address Allowlist Entry
+///
+/// Each pre-cleared (e.g. KYC'd) address has its own PDA account, mirroring
+/// `BlacklistEntry`'s rent-efficient, enumerable design. Allowlisting an
+/// address short-circuits it to approved in `is_approved`, but only after
+/// the oracle sanction checks — a sanctioned address can never be approved
+/// via the allowlist.
+///
+/// # Seeds
+/// `["allowlist", account.as_ref()]`
+///
+/// # Space Calculation
+/// - discriminator: 8 bytes
+/// - account: 32 bytes
+/// - allowlisted: 1 byte
+/// - timestamp: 8 bytes
+/// - bump: 1 byte
+/// - Total: 50 bytes
+#[account]
+#[derive(InitSpace)]
+pub struct AllowlistEntry {
+    /// The address being allowlisted
+    pub account: Pubkey,
+
+    /// Allowlist status
+    /// If true, this address is pre-cleared
+    /// If false but account exists, entry is stale and should be closed
+    pub allowlisted: bool,
+
+    /// Unix timestamp when this entry was created
+    pub timestamp: i64,
+
+    /// PDA bump for this account
+    pub bump: u8,
+}
+
+/// Whitelisted trusted program entry
+///
+/// # Space Calculation
+/// - program: 32 bytes
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq)]
+pub struct WhitelistEntry {
+    /// The trusted program's address
+    pub program: Pubkey,
+}
+
+/// Number of entries retained by the [`AuditLog`] ring buffer
+pub const AUDIT_LOG_CAPACITY: usize = 256;
+
+/// Kind of mutation recorded by an [`AuditLogEntry`]
+///
+/// # Space Calculation
+/// - discriminant: 1 byte
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq)]
+pub enum AuditAction {
+    /// A `set_blacklisted` / `set_blacklisted_batch` mutation, or an address
+    /// from `initialize`'s initial blacklist
+    Blacklist,
+    /// An owner called `transfer_ownership`
+    OwnershipTransferInitiated,
+    /// The pending owner called `accept_ownership`
+    OwnershipTransferAccepted,
+    /// The owner called `cancel_ownership_transfer`
+    OwnershipTransferCancelled,
+    /// The recovery authority called `recover_ownership`
+    OwnershipRecovered,
+}
+
+impl Default for AuditAction {
+    fn default() -> Self {
+        AuditAction::Blacklist
+    }
+}
+
+/// Append-only ring-buffer audit log of blacklist mutations and ownership
+/// changes
+///
+/// Off-chain indexers and regulators can reconstruct the full compliance
+/// history of the registry from this single PDA without scraping
+/// transaction logs. Once full, new entries overwrite the oldest slot;
+/// `head` keeps increasing (never wraps) so consumers can detect that they
+/// missed entries between polls, while `count` (capped at
+/// `AUDIT_LOG_CAPACITY`) tells a reader how many of the slots hold valid
+/// data before the buffer has wrapped for the first time.
+///
+/// # Seeds
+/// `["audit_log"]`
+///
+/// # Space Calculation
+/// - discriminator: 8 bytes
+/// - head: 8 bytes
+/// - count: 8 bytes
+/// - entries: AUDIT_LOG_CAPACITY * 74 bytes
+/// - bump: 1 byte
+#[account]
+#[derive(InitSpace)]
+pub struct AuditLog {
+    /// Total number of entries ever written; the write slot is
+    /// `head % AUDIT_LOG_CAPACITY`. Never wraps.
+    pub head: u64,
+
+    /// Number of valid entries in `entries`, capped at `AUDIT_LOG_CAPACITY`
+    pub count: u64,
+
+    /// Fixed-size circular buffer of the most recent entries
+    pub entries: [AuditLogEntry; AUDIT_LOG_CAPACITY],
+
+    /// PDA bump for this account
+    pub bump: u8,
+}
+
+/// Single record in the [`AuditLog`] ring buffer
+///
+/// # Space Calculation
+/// - timestamp: 8 bytes
+/// - actor: 32 bytes
+/// - target: 32 bytes
+/// - action: 1 byte
+/// - blacklisted: 1 byte
+/// - Total: 74 bytes
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Default)]
+pub struct AuditLogEntry {
+    /// Unix timestamp when the mutation was recorded
+    pub timestamp: i64,
+
+    /// The authority that performed the mutation
+    pub actor: Pubkey,
+
+    /// The address the mutation concerns: the (un)blacklisted address, or
+    /// the relevant pending/new owner for ownership-transfer actions
+    pub target: Pubkey,
+
+    /// The kind of mutation this entry records
+    pub action: AuditAction,
+
+    /// For `AuditAction::Blacklist` entries, the new blacklist status.
+    /// Unused (false) for ownership-transfer actions.
+    pub blacklisted: bool,
+}
+
+impl AuditLog {
+    /// Appends an entry, overwriting the oldest slot once the buffer is full
+    pub fn push(&mut self, entry: AuditLogEntry) {
+        let index = (self.head % AUDIT_LOG_CAPACITY as u64) as usize;
+        self.entries[index] = entry;
+        self.head = self.head.wrapping_add(1);
+        self.count = self.count.saturating_add(1).min(AUDIT_LOG_CAPACITY as u64);
+    }
+}
+
 impl AccessRegistry {
     /// Check if an address is the registry owner
     pub fn is_registry_owner(&self, address: &Pubkey) -> bool {
@@ -100,6 +305,11 @@ impl AccessRegistry {
         self.is_registry_owner(address) || self.is_pool_factory_owner(address)
     }
 
+    /// Check if an address is a whitelisted trusted program
+    pub fn is_whitelisted(&self, address: &Pubkey) -> bool {
+        self.whitelist.iter().any(|entry| &entry.program == address)
+    }
+
     /// Check if the Chainalysis oracle is configured
     pub fn has_oracle(&self) -> bool {
         self.chainalysis_oracle != Pubkey::default()
@@ -109,4 +319,15 @@ impl AccessRegistry {
     pub fn has_pending_owner(&self) -> bool {
         self.pending_owner != Pubkey::default()
     }
+
+    /// Check if any multi-oracle quorum members are configured
+    pub fn has_oracles(&self) -> bool {
+        !self.oracles.is_empty()
+    }
+
+    /// Current Unix timestamp as seen by the ownership-transfer timelock,
+    /// adjusted by `time_offset` (see its doc comment)
+    pub fn timelock_now(&self) -> Result<i64> {
+        Ok(Clock::get()?.unix_timestamp.saturating_add(self.time_offset))
+    }
 }